@@ -0,0 +1,111 @@
+use std::fmt;
+use std::time::Duration;
+
+use datafusion::common::{DFSchemaRef, Result};
+use datafusion::logical_expr::{Expr, LogicalPlan, UserDefinedLogicalNodeCore};
+
+/// The bounds of a streaming interval (band) join, expressed as offsets from the peer
+/// side's `_timestamp`.
+///
+/// For a predicate such as
+/// `left._timestamp BETWEEN right._timestamp - INTERVAL '1 hour' AND right._timestamp + INTERVAL '30 minutes'`,
+/// `lower` is `1 hour` and `upper` is `30 minutes`: a left row can only match right rows
+/// whose timestamp falls within `[left._timestamp - upper, left._timestamp + lower]`,
+/// and a right row can only match left rows whose timestamp falls within
+/// `[right._timestamp - lower, right._timestamp + upper]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IntervalJoinBounds {
+    pub lower: Duration,
+    pub upper: Duration,
+}
+
+impl IntervalJoinBounds {
+    /// How long a row must be retained, relative to the watermark, before it is
+    /// guaranteed not to match any future peer row. This is the eviction horizon used
+    /// to bound per-key state instead of a blanket TTL.
+    pub fn retention(&self) -> Duration {
+        self.lower.max(self.upper)
+    }
+}
+
+/// An interval (band) join between two unbounded, non-windowed streams, matched via a
+/// bounded time-range predicate on the two sides' `_timestamp` columns rather than a
+/// shared tumbling/sliding window.
+///
+/// Each side retains only the rows that can still match a future peer, evicting keyed
+/// state once the watermark advances past `bounds.retention()` beyond a row's
+/// timestamp. This gives the updating-join behavior of joining two never-ending streams
+/// without the unbounded (TTL-only) state growth of [`super::join::JoinExtension`].
+///
+/// The physical planner reads `bounds` off this node (the same way it reads
+/// [`super::join::JoinExtension::ttl`]) to size the keyed state it retains for each
+/// side instead of falling back to a blanket TTL.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IntervalJoinExtension {
+    pub rewritten_join: LogicalPlan,
+    pub bounds: IntervalJoinBounds,
+}
+
+impl UserDefinedLogicalNodeCore for IntervalJoinExtension {
+    fn name(&self) -> &str {
+        "IntervalJoinExtension"
+    }
+
+    fn inputs(&self) -> Vec<&LogicalPlan> {
+        vec![&self.rewritten_join]
+    }
+
+    fn schema(&self) -> &DFSchemaRef {
+        self.rewritten_join.schema()
+    }
+
+    fn expressions(&self) -> Vec<Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "IntervalJoinExtension: lower={:?}, upper={:?}",
+            self.bounds.lower, self.bounds.upper
+        )
+    }
+
+    fn with_exprs_and_inputs(&self, exprs: Vec<Expr>, inputs: Vec<LogicalPlan>) -> Result<Self> {
+        if !exprs.is_empty() {
+            return Err(datafusion::common::DataFusionError::Plan(
+                "IntervalJoinExtension does not take expressions".into(),
+            ));
+        }
+        let [rewritten_join] = <[LogicalPlan; 1]>::try_from(inputs).map_err(|inputs| {
+            datafusion::common::DataFusionError::Plan(format!(
+                "IntervalJoinExtension expects exactly one input, got {}",
+                inputs.len()
+            ))
+        })?;
+        Ok(Self {
+            rewritten_join,
+            bounds: self.bounds.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retention_is_the_larger_of_the_two_bounds() {
+        let bounds = IntervalJoinBounds {
+            lower: Duration::from_secs(3600),
+            upper: Duration::from_secs(1800),
+        };
+        assert_eq!(bounds.retention(), Duration::from_secs(3600));
+
+        let bounds = IntervalJoinBounds {
+            lower: Duration::from_secs(60),
+            upper: Duration::from_secs(120),
+        };
+        assert_eq!(bounds.retention(), Duration::from_secs(120));
+    }
+}
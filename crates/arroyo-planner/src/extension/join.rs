@@ -0,0 +1,98 @@
+use std::fmt;
+use std::time::Duration;
+
+use datafusion::common::{DFSchemaRef, Result};
+use datafusion::logical_expr::{Expr, JoinType, LogicalPlan, UserDefinedLogicalNodeCore};
+
+/// A join rewritten onto keyed inputs (see `create_join_key_plan`), either evaluated
+/// per window for two inputs that share the same window (`is_instant`) or kept
+/// updating with TTL'd keyed state on both sides.
+///
+/// For an updating `LEFT`/`RIGHT`/`FULL` join, `join_type` is what tells the physical
+/// operator to null-pad an unmatched row on the preserved side instead of dropping it,
+/// and to emit a retraction through `UPDATING_META_FIELD` when a previously null-padded
+/// row later finds (or loses) a match. `ttl` continues to bound how long keyed state is
+/// retained for these variants the same way it already does for updating inner joins.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JoinExtension {
+    pub rewritten_join: LogicalPlan,
+    pub join_type: JoinType,
+    pub is_instant: bool,
+    pub ttl: Option<Duration>,
+}
+
+impl UserDefinedLogicalNodeCore for JoinExtension {
+    fn name(&self) -> &str {
+        "JoinExtension"
+    }
+
+    fn inputs(&self) -> Vec<&LogicalPlan> {
+        vec![&self.rewritten_join]
+    }
+
+    fn schema(&self) -> &DFSchemaRef {
+        self.rewritten_join.schema()
+    }
+
+    fn expressions(&self) -> Vec<Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "JoinExtension: join_type={:?}, is_instant={}, ttl={:?}",
+            self.join_type, self.is_instant, self.ttl
+        )
+    }
+
+    fn with_exprs_and_inputs(&self, exprs: Vec<Expr>, inputs: Vec<LogicalPlan>) -> Result<Self> {
+        if !exprs.is_empty() {
+            return Err(datafusion::common::DataFusionError::Plan(
+                "JoinExtension does not take expressions".into(),
+            ));
+        }
+        let [rewritten_join] = <[LogicalPlan; 1]>::try_from(inputs).map_err(|inputs| {
+            datafusion::common::DataFusionError::Plan(format!(
+                "JoinExtension expects exactly one input, got {}",
+                inputs.len()
+            ))
+        })?;
+        Ok(Self {
+            rewritten_join,
+            join_type: self.join_type,
+            is_instant: self.is_instant,
+            ttl: self.ttl,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::common::DFSchema;
+    use datafusion::logical_expr::EmptyRelation;
+    use std::sync::Arc;
+
+    fn empty_relation() -> LogicalPlan {
+        LogicalPlan::EmptyRelation(EmptyRelation {
+            produce_one_row: false,
+            schema: Arc::new(DFSchema::empty()),
+        })
+    }
+
+    #[test]
+    fn with_exprs_and_inputs_preserves_join_type_and_ttl() {
+        let extension = JoinExtension {
+            rewritten_join: empty_relation(),
+            join_type: JoinType::Left,
+            is_instant: false,
+            ttl: Some(Duration::from_secs(60)),
+        };
+        let rewritten = extension
+            .with_exprs_and_inputs(vec![], vec![empty_relation()])
+            .unwrap();
+        assert_eq!(rewritten.join_type, JoinType::Left);
+        assert_eq!(rewritten.ttl, Some(Duration::from_secs(60)));
+    }
+}
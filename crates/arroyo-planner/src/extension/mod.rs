@@ -0,0 +1,2 @@
+pub mod interval_join;
+pub mod join;
@@ -1,40 +1,59 @@
+use crate::extension::interval_join::{IntervalJoinBounds, IntervalJoinExtension};
 use crate::extension::join::JoinExtension;
 use crate::extension::key_calculation::KeyCalculationExtension;
 use crate::plan::WindowDetectingVisitor;
 use crate::{fields_with_qualifiers, schema_from_df_fields_with_metadata, ArroyoSchemaProvider};
 use arroyo_datastream::WindowType;
 use arroyo_rpc::UPDATING_META_FIELD;
-use datafusion::common::tree_node::{Transformed, TreeNodeRewriter};
+use datafusion::arrow::datatypes::{DataType, IntervalDayTimeType, IntervalMonthDayNanoType};
+use datafusion::common::tree_node::{Transformed, TreeNodeRecursion, TreeNodeRewriter};
 use datafusion::common::{
     not_impl_err, plan_err, Column, DataFusionError, JoinConstraint, JoinType, Result, ScalarValue,
     TableReference,
 };
 use datafusion::logical_expr;
-use datafusion::logical_expr::expr::Alias;
+use datafusion::logical_expr::expr::{Alias, Between};
+use datafusion::logical_expr::type_coercion::binary::comparison_coercion;
 use datafusion::logical_expr::{
-    build_join_schema, BinaryExpr, Case, Expr, Extension, Join, LogicalPlan, Projection,
+    build_join_schema, BinaryExpr, Case, Expr, Extension, Filter, Join, LogicalPlan, Operator,
+    Projection,
 };
 use datafusion::prelude::coalesce;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub(crate) struct JoinRewriter<'a> {
     pub schema_provider: &'a ArroyoSchemaProvider,
 }
 
+/// Stand-in for a NULL key component in a `null_equals_null` (`IS NOT DISTINCT FROM`)
+/// join. Two NULL components are both replaced by this exact string, so they compare
+/// equal as ordinary (non-null) `Utf8` values instead of `NULL = NULL` evaluating to
+/// NULL/unknown and the row being excluded.
+///
+/// This is an in-band sentinel, not a separate out-of-band marker: a `Utf8` join key
+/// could in principle hold this exact string as genuine data, in which case that row
+/// would be (incorrectly) treated as NULL-matching. The leading NUL byte makes this
+/// astronomically unlikely in practice, but it is not provably impossible.
+const NULL_SAFE_KEY_SENTINEL: &str = "\u{0}_arroyo_null_equals_null_sentinel";
+
 impl JoinRewriter<'_> {
     fn check_join_windowing(join: &Join) -> Result<bool> {
         let left_window = WindowDetectingVisitor::get_window(&join.left)?;
         let right_window = WindowDetectingVisitor::get_window(&join.right)?;
         match (left_window, right_window) {
-            (None, None) => {
-                if join.join_type == JoinType::Inner {
-                    Ok(false)
-                } else {
-                    Err(DataFusionError::NotImplemented(
-                        "can't handle non-inner joins without windows".into(),
-                    ))
-                }
-            }
+            (None, None) => match join.join_type {
+                // Updating joins keep keyed state on both sides indefinitely (bounded
+                // by `ttl`), so outer joins can be evaluated the same way as inner ones:
+                // null-pad an unmatched side and retract the null-padded row through
+                // `JoinExtension`/`UPDATING_META_FIELD` once a match later arrives.
+                JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full => Ok(false),
+                _ => Err(DataFusionError::NotImplemented(format!(
+                    "can't handle {:?} joins without windows",
+                    join.join_type
+                ))),
+            },
             (None, Some(_)) => Err(DataFusionError::NotImplemented(
                 "can't handle mixed windowing between left (non-windowed) and right (windowed)."
                     .into(),
@@ -77,10 +96,34 @@ impl JoinRewriter<'_> {
         Ok(())
     }
 
+    /// Encodes a single join key component so that NULL compares equal to NULL.
+    ///
+    /// Each `_key_N` column is encoded independently, so this composes correctly for
+    /// multi-column keys where only some components are NULL: e.g. a left row with key
+    /// `(NULL, 5)` only matches a right row with key `(NULL, 5)`, because the first
+    /// component of both is replaced with the same sentinel and the second is compared
+    /// as an ordinary (non-null) value.
+    fn null_safe_key_expr(expr: Expr) -> Expr {
+        Expr::Case(Case {
+            expr: None,
+            when_then_expr: vec![(
+                Box::new(expr.clone().is_null()),
+                Box::new(Expr::Literal(ScalarValue::Utf8(Some(
+                    NULL_SAFE_KEY_SENTINEL.to_string(),
+                )))),
+            )],
+            else_expr: Some(Box::new(Expr::Cast(logical_expr::Cast {
+                expr: Box::new(expr),
+                data_type: DataType::Utf8,
+            }))),
+        })
+    }
+
     fn create_join_key_plan(
         &self,
         input: Arc<LogicalPlan>,
         join_expressions: Vec<Expr>,
+        null_equals_null: bool,
         name: &'static str,
     ) -> Result<LogicalPlan> {
         let key_count = join_expressions.len();
@@ -89,6 +132,11 @@ impl JoinRewriter<'_> {
             .into_iter()
             .enumerate()
             .map(|(index, expr)| {
+                let expr = if null_equals_null {
+                    Self::null_safe_key_expr(expr)
+                } else {
+                    expr
+                };
                 expr.alias_qualified(
                     Some(TableReference::bare("_arroyo")),
                     format!("_key_{}", index),
@@ -187,6 +235,265 @@ impl JoinRewriter<'_> {
             output_schema.clone(),
         )?))
     }
+
+    fn timestamp_column(plan: &LogicalPlan) -> Result<Column> {
+        fields_with_qualifiers(plan.schema())
+            .into_iter()
+            .find(|field| field.name() == "_timestamp")
+            .map(|field| field.qualified_column())
+            .ok_or_else(|| DataFusionError::Plan("join input is missing a _timestamp column".into()))
+    }
+
+    /// Interprets an interval literal as a fixed [`Duration`], for use as an interval
+    /// join bound. Calendar-relative intervals (non-zero months) can't be expressed as
+    /// a fixed duration, so those return `None`.
+    fn interval_literal_as_duration(expr: &Expr) -> Option<Duration> {
+        let Expr::Literal(scalar) = expr else {
+            return None;
+        };
+        match scalar {
+            ScalarValue::IntervalDayTime(Some(value)) => {
+                let (days, millis) = IntervalDayTimeType::to_parts(*value);
+                if days < 0 || millis < 0 {
+                    return None;
+                }
+                Some(Duration::from_secs(days as u64 * 86_400) + Duration::from_millis(millis as u64))
+            }
+            ScalarValue::IntervalMonthDayNano(Some(value)) => {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(*value);
+                if months != 0 || days < 0 || nanos < 0 {
+                    return None;
+                }
+                Some(Duration::from_secs(days as u64 * 86_400) + Duration::from_nanos(nanos as u64))
+            }
+            _ => None,
+        }
+    }
+
+    /// Matches `peer +/- INTERVAL` and returns the signed offset as a duration relative
+    /// to `peer`, where a `-` produces a positive offset (the bound is before `peer`)
+    /// and a `+` a negative one (the bound is after `peer`).
+    fn peer_offset(expr: &Expr, peer: &Column) -> Option<(Duration, bool)> {
+        let Expr::BinaryExpr(BinaryExpr { left, op, right }) = expr else {
+            return None;
+        };
+        let Expr::Column(column) = left.as_ref() else {
+            return None;
+        };
+        if column != peer {
+            return None;
+        }
+        let duration = Self::interval_literal_as_duration(right)?;
+        match op {
+            Operator::Minus => Some((duration, true)),
+            Operator::Plus => Some((duration, false)),
+            _ => None,
+        }
+    }
+
+    /// Decomposes a filter expression into its top-level AND conjuncts.
+    fn conjuncts(expr: Expr) -> Vec<Expr> {
+        match expr {
+            Expr::BinaryExpr(BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            }) => {
+                let mut exprs = Self::conjuncts(*left);
+                exprs.extend(Self::conjuncts(*right));
+                exprs
+            }
+            other => vec![other],
+        }
+    }
+
+    /// ANDs `conjunct` onto `existing`, starting a fresh expression if `existing` is
+    /// `None`.
+    fn conjoin(existing: Option<Expr>, conjunct: Expr) -> Option<Expr> {
+        Some(match existing {
+            Some(existing) => existing.and(conjunct),
+            None => conjunct,
+        })
+    }
+
+    /// ANDs two optional expressions together, passing either through unchanged if the
+    /// other is `None`.
+    fn conjoin_opt(left: Option<Expr>, right: Option<Expr>) -> Option<Expr> {
+        match (left, right) {
+            (Some(left), Some(right)) => Some(left.and(right)),
+            (Some(expr), None) | (None, Some(expr)) => Some(expr),
+            (None, None) => None,
+        }
+    }
+
+    /// Restricts `left_only`/`right_only` pushdown to sides that aren't preserved by
+    /// `join_type`, folding a disallowed conjunct back into `remaining` (the join
+    /// filter) instead of dropping it.
+    ///
+    /// `join.filter` is the ON-clause condition, so pushing a single-sided conjunct
+    /// into its input is only safe when that side isn't preserved: a preserved side's
+    /// conjunct is part of the match condition, and an unmatched row on that side must
+    /// still survive, null-padded, rather than be filtered out of its input beforehand.
+    /// INNER preserves neither side (both push), LEFT/RIGHT preserve left/right
+    /// respectively (only push into the other side), and FULL preserves both (push
+    /// neither).
+    fn gate_pushdown_by_join_type(
+        join_type: JoinType,
+        left_only: Option<Expr>,
+        right_only: Option<Expr>,
+        remaining: Option<Expr>,
+    ) -> (Option<Expr>, Option<Expr>, Option<Expr>) {
+        let (left_only, remaining) = if matches!(join_type, JoinType::Inner | JoinType::Right) {
+            (left_only, remaining)
+        } else {
+            (None, Self::conjoin_opt(remaining, left_only))
+        };
+        let (right_only, remaining) = if matches!(join_type, JoinType::Inner | JoinType::Left) {
+            (right_only, remaining)
+        } else {
+            (None, Self::conjoin_opt(remaining, right_only))
+        };
+        (left_only, right_only, remaining)
+    }
+
+    /// Returns the set of columns referenced anywhere in `expr`.
+    fn column_refs(expr: &Expr) -> Result<HashSet<Column>> {
+        let mut columns = HashSet::new();
+        expr.apply(|e| {
+            if let Expr::Column(column) = e {
+                columns.insert(column.clone());
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(columns)
+    }
+
+    /// Splits `filter` into three pieces: conjuncts that reference only columns of
+    /// `left`, conjuncts that reference only columns of `right`, and the remaining
+    /// (cross-side, or referencing a synthetic `_key_N` join key column) conjuncts that
+    /// must stay on the join itself.
+    fn split_single_sided_filters(
+        filter: Expr,
+        left: &LogicalPlan,
+        right: &LogicalPlan,
+    ) -> Result<(Option<Expr>, Option<Expr>, Option<Expr>)> {
+        let mut left_only = None;
+        let mut right_only = None;
+        let mut remaining = None;
+        for conjunct in Self::conjuncts(filter) {
+            let columns = Self::column_refs(&conjunct)?;
+            let references_key_column = columns.iter().any(|column| column.name.starts_with("_key_"));
+            let in_left = !references_key_column
+                && !columns.is_empty()
+                && columns.iter().all(|column| left.schema().index_of_column(column).is_ok());
+            let in_right = !references_key_column
+                && !columns.is_empty()
+                && columns.iter().all(|column| right.schema().index_of_column(column).is_ok());
+            if in_left {
+                left_only = Self::conjoin(left_only, conjunct);
+            } else if in_right {
+                right_only = Self::conjoin(right_only, conjunct);
+            } else {
+                remaining = Self::conjoin(remaining, conjunct);
+            }
+        }
+        Ok((left_only, right_only, remaining))
+    }
+
+    /// Looks for a bounded time-range (band) predicate between `left_ts` and
+    /// `right_ts` among `filter`'s conjuncts, of the form
+    /// `side._timestamp BETWEEN peer._timestamp - INTERVAL low AND peer._timestamp + INTERVAL high`
+    /// (on either side). The bounds are expressed relative to the left side as
+    /// `IntervalJoinBounds` defines them: for a left-side predicate `lower` is `low`
+    /// and `upper` is `high`, and for a right-side predicate the two are swapped.
+    /// If found, returns the extracted bounds together with the
+    /// remaining filter built from the other conjuncts, which still needs to be
+    /// evaluated as a regular join filter.
+    fn extract_interval_join_bounds(
+        filter: Expr,
+        left_ts: &Column,
+        right_ts: &Column,
+    ) -> (Option<IntervalJoinBounds>, Option<Expr>) {
+        let mut bounds = None;
+        let mut remaining: Option<Expr> = None;
+        for conjunct in Self::conjuncts(filter) {
+            if bounds.is_none() {
+                if let Expr::Between(Between {
+                    expr,
+                    negated: false,
+                    low,
+                    high,
+                }) = &conjunct
+                {
+                    let side = match expr.as_ref() {
+                        Expr::Column(column) if column == left_ts => Some(true),
+                        Expr::Column(column) if column == right_ts => Some(false),
+                        _ => None,
+                    };
+                    if let Some(is_left) = side {
+                        let peer = if is_left { right_ts } else { left_ts };
+                        let low_offset = Self::peer_offset(low, peer);
+                        let high_offset = Self::peer_offset(high, peer);
+                        // Only a `peer - lower` / `peer + upper` band matches the shape
+                        // `IntervalJoinBounds` assumes; any other sign combination (e.g.
+                        // both endpoints `+`, as in `BETWEEN peer + 1h AND peer + 2h`)
+                        // isn't a simple band around `peer`, so leave it as an ordinary
+                        // filter conjunct rather than silently mis-decoding its bounds.
+                        if let (Some((low_duration, true)), Some((high_duration, false))) =
+                            (low_offset, high_offset)
+                        {
+                            // `expr BETWEEN peer - low AND peer + high`
+                            bounds = Some(if is_left {
+                                IntervalJoinBounds {
+                                    lower: low_duration,
+                                    upper: high_duration,
+                                }
+                            } else {
+                                IntervalJoinBounds {
+                                    lower: high_duration,
+                                    upper: low_duration,
+                                }
+                            });
+                            continue;
+                        }
+                    }
+                }
+            }
+            remaining = Self::conjoin(remaining, conjunct);
+        }
+        (bounds, remaining)
+    }
+
+    /// Casts each equijoin key pair to a common comparison type, so e.g. an `Int32`
+    /// column can be joined against an `Int64` one, or two `Decimal128` columns of
+    /// different precision/scale. Erroring only when DataFusion's own comparison
+    /// coercion can't find a common type for the pair keeps this in sync with the
+    /// types DataFusion itself considers comparable elsewhere (binary expressions,
+    /// `IN` lists, etc).
+    fn coerce_join_keys(
+        on: Vec<(Expr, Expr)>,
+        left: &LogicalPlan,
+        right: &LogicalPlan,
+    ) -> Result<Vec<(Expr, Expr)>> {
+        on.into_iter()
+            .map(|(left_expr, right_expr)| {
+                let left_type = left_expr.get_type(left.schema().as_ref())?;
+                let right_type = right_expr.get_type(right.schema().as_ref())?;
+                if left_type == right_type {
+                    return Ok((left_expr, right_expr));
+                }
+                let common_type = comparison_coercion(&left_type, &right_type).ok_or_else(|| {
+                    DataFusionError::Plan(format!(
+                        "can't find a common type to join keys of type {left_type:?} and {right_type:?}"
+                    ))
+                })?;
+                Ok((
+                    left_expr.cast_to(&common_type, left.schema().as_ref())?,
+                    right_expr.cast_to(&common_type, right.schema().as_ref())?,
+                ))
+            })
+            .collect()
+    }
 }
 
 impl TreeNodeRewriter for JoinRewriter<'_> {
@@ -202,11 +509,11 @@ impl TreeNodeRewriter for JoinRewriter<'_> {
             left,
             right,
             on,
-            filter,
+            mut filter,
             join_type,
             join_constraint: JoinConstraint::On,
             schema: _,
-            null_equals_null: false,
+            null_equals_null,
         } = join
         else {
             return not_impl_err!("can't handle join constraint other than ON");
@@ -217,11 +524,67 @@ impl TreeNodeRewriter for JoinRewriter<'_> {
             return not_impl_err!("Updating joins must include an equijoin condition");
         }
 
+        // An interval (band) join is an unwindowed inner join whose filter bounds the
+        // two sides' `_timestamp` columns to a time range of each other; it trades the
+        // TTL-based state of a regular updating join for state bounded by the
+        // watermark once a row can no longer match a future peer.
+        let interval_bounds = if !is_instant && join_type == JoinType::Inner {
+            let left_ts = Self::timestamp_column(&left)?;
+            let right_ts = Self::timestamp_column(&right)?;
+            match filter {
+                Some(filter_expr) => {
+                    let (bounds, remaining) =
+                        Self::extract_interval_join_bounds(filter_expr, &left_ts, &right_ts);
+                    filter = remaining;
+                    bounds
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        // Push single-sided conjuncts down to the side they reference so an updating
+        // join doesn't have to materialize full state before evaluating them; only
+        // genuinely cross-side predicates (and anything referencing a synthetic
+        // `_key_N` column) remain on the join filter. Pushdown is gated by join type so
+        // a preserved side's conjuncts stay on the join filter instead of filtering out
+        // rows that must survive null-padded; see `gate_pushdown_by_join_type`.
+        let (left, right, filter) = match filter {
+            Some(filter_expr) => {
+                let (left_only, right_only, remaining) =
+                    Self::split_single_sided_filters(filter_expr, &left, &right)?;
+                let (left_only, right_only, remaining) =
+                    Self::gate_pushdown_by_join_type(join_type, left_only, right_only, remaining);
+                let left = match left_only {
+                    Some(predicate) => {
+                        Arc::new(LogicalPlan::Filter(Filter::try_new(predicate, left)?))
+                    }
+                    None => left,
+                };
+                let right = match right_only {
+                    Some(predicate) => {
+                        Arc::new(LogicalPlan::Filter(Filter::try_new(predicate, right)?))
+                    }
+                    None => right,
+                };
+                (left, right, remaining)
+            }
+            None => (left, right, None),
+        };
+
+        // Coerce each key pair to a common comparison type before aliasing into
+        // `_key_N`, so the downstream keyed join can compare the physical key values
+        // directly instead of requiring byte-identical Arrow types on both sides.
+        let on = Self::coerce_join_keys(on, &left, &right)?;
+
         let (left_expressions, right_expressions): (Vec<_>, Vec<_>) =
             on.clone().into_iter().unzip();
 
-        let left_input = self.create_join_key_plan(left, left_expressions, "left")?;
-        let right_input = self.create_join_key_plan(right, right_expressions, "right")?;
+        let left_input =
+            self.create_join_key_plan(left, left_expressions, null_equals_null, "left")?;
+        let right_input =
+            self.create_join_key_plan(right, right_expressions, null_equals_null, "right")?;
         let rewritten_join = LogicalPlan::Join(Join {
             schema: Arc::new(build_join_schema(
                 left_input.schema(),
@@ -233,14 +596,25 @@ impl TreeNodeRewriter for JoinRewriter<'_> {
             on,
             join_type,
             join_constraint: JoinConstraint::On,
-            null_equals_null: false,
+            null_equals_null,
             filter,
         });
 
         let final_logical_plan = self.post_join_timestamp_projection(rewritten_join)?;
 
+        if let Some(bounds) = interval_bounds {
+            let interval_join_extension = IntervalJoinExtension {
+                rewritten_join: final_logical_plan,
+                bounds,
+            };
+            return Ok(Transformed::yes(LogicalPlan::Extension(Extension {
+                node: Arc::new(interval_join_extension),
+            })));
+        }
+
         let join_extension = JoinExtension {
             rewritten_join: final_logical_plan,
+            join_type,
             is_instant,
             // only non-instant (updating) joins have a TTL
             ttl: (!is_instant).then_some(self.schema_provider.planning_options.ttl),
@@ -251,3 +625,201 @@ impl TreeNodeRewriter for JoinRewriter<'_> {
         })))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::common::DFSchema;
+    use datafusion::logical_expr::EmptyRelation;
+
+    fn relation(table: &str, fields: &[(&str, DataType)]) -> LogicalPlan {
+        let schema = datafusion::arrow::datatypes::Schema::new(
+            fields
+                .iter()
+                .map(|(name, data_type)| {
+                    datafusion::arrow::datatypes::Field::new(*name, data_type.clone(), true)
+                })
+                .collect::<Vec<_>>(),
+        );
+        let dfschema = DFSchema::try_from_qualified_schema(table, &schema).unwrap();
+        LogicalPlan::EmptyRelation(EmptyRelation {
+            produce_one_row: false,
+            schema: Arc::new(dfschema),
+        })
+    }
+
+    fn col(table: &str, name: &str) -> Expr {
+        Expr::Column(Column::new(Some(TableReference::bare(table)), name))
+    }
+
+    fn days_interval(days: i32) -> Expr {
+        Expr::Literal(ScalarValue::IntervalDayTime(Some(
+            datafusion::arrow::datatypes::IntervalDayTimeType::make_value(days, 0),
+        )))
+    }
+
+    #[test]
+    fn peer_offset_reports_the_operator_sign() {
+        let peer = Column::new(Some(TableReference::bare("r")), "_timestamp");
+        let minus = col("r", "_timestamp") - days_interval(1);
+        let plus = col("r", "_timestamp") + days_interval(1);
+        assert_eq!(
+            JoinRewriter::peer_offset(&minus, &peer),
+            Some((Duration::from_secs(86_400), true))
+        );
+        assert_eq!(
+            JoinRewriter::peer_offset(&plus, &peer),
+            Some((Duration::from_secs(86_400), false))
+        );
+    }
+
+    #[test]
+    fn extract_interval_join_bounds_decodes_a_minus_plus_band() {
+        let left_ts = Column::new(Some(TableReference::bare("l")), "_timestamp");
+        let right_ts = Column::new(Some(TableReference::bare("r")), "_timestamp");
+        let filter = Expr::Between(Between {
+            expr: Box::new(col("l", "_timestamp")),
+            negated: false,
+            low: Box::new(col("r", "_timestamp") - days_interval(1)),
+            high: Box::new(col("r", "_timestamp") + days_interval(2)),
+        });
+        let (bounds, remaining) =
+            JoinRewriter::extract_interval_join_bounds(filter, &left_ts, &right_ts);
+        assert_eq!(
+            bounds,
+            Some(IntervalJoinBounds {
+                lower: Duration::from_secs(86_400),
+                upper: Duration::from_secs(172_800),
+            })
+        );
+        assert!(remaining.is_none());
+    }
+
+    #[test]
+    fn extract_interval_join_bounds_rejects_a_same_sign_band() {
+        // `left BETWEEN right + 1d AND right + 2d` isn't a band around `right` in the
+        // `peer - lower`/`peer + upper` shape `IntervalJoinBounds` assumes, so it must
+        // be left as an ordinary filter conjunct rather than mis-decoded.
+        let left_ts = Column::new(Some(TableReference::bare("l")), "_timestamp");
+        let right_ts = Column::new(Some(TableReference::bare("r")), "_timestamp");
+        let filter = Expr::Between(Between {
+            expr: Box::new(col("l", "_timestamp")),
+            negated: false,
+            low: Box::new(col("r", "_timestamp") + days_interval(1)),
+            high: Box::new(col("r", "_timestamp") + days_interval(2)),
+        });
+        let (bounds, remaining) =
+            JoinRewriter::extract_interval_join_bounds(filter.clone(), &left_ts, &right_ts);
+        assert_eq!(bounds, None);
+        assert_eq!(remaining, Some(filter));
+    }
+
+    #[test]
+    fn gate_pushdown_by_join_type_blocks_the_preserved_side() {
+        let left_only = Some(col("l", "a").gt(Expr::Literal(ScalarValue::Int32(Some(5)))));
+        let right_only = Some(col("r", "b").lt(Expr::Literal(ScalarValue::Int32(Some(10)))));
+
+        // INNER: both push, nothing remains.
+        let (l, r, rem) = JoinRewriter::gate_pushdown_by_join_type(
+            JoinType::Inner,
+            left_only.clone(),
+            right_only.clone(),
+            None,
+        );
+        assert!(l.is_some() && r.is_some() && rem.is_none());
+
+        // LEFT preserves `left`, so its conjunct must stay on the join filter instead
+        // of being pushed into `left`'s input.
+        let (l, r, rem) = JoinRewriter::gate_pushdown_by_join_type(
+            JoinType::Left,
+            left_only.clone(),
+            right_only.clone(),
+            None,
+        );
+        assert!(l.is_none() && r.is_some() && rem.is_some());
+
+        // RIGHT preserves `right`.
+        let (l, r, rem) = JoinRewriter::gate_pushdown_by_join_type(
+            JoinType::Right,
+            left_only.clone(),
+            right_only.clone(),
+            None,
+        );
+        assert!(l.is_some() && r.is_none() && rem.is_some());
+
+        // FULL preserves both sides, so neither conjunct is pushed.
+        let (l, r, rem) =
+            JoinRewriter::gate_pushdown_by_join_type(JoinType::Full, left_only, right_only, None);
+        assert!(l.is_none() && r.is_none() && rem.is_some());
+    }
+
+    #[test]
+    fn coerce_join_keys_widens_int32_to_int64() {
+        let left = relation("l", &[("a", DataType::Int32)]);
+        let right = relation("r", &[("b", DataType::Int64)]);
+        let on = vec![(col("l", "a"), col("r", "b"))];
+        let coerced = JoinRewriter::coerce_join_keys(on, &left, &right).unwrap();
+        assert_eq!(coerced.len(), 1);
+        let (left_expr, right_expr) = &coerced[0];
+        assert_eq!(
+            left_expr.get_type(left.schema().as_ref()).unwrap(),
+            DataType::Int64
+        );
+        assert_eq!(
+            right_expr.get_type(right.schema().as_ref()).unwrap(),
+            DataType::Int64
+        );
+    }
+
+    #[test]
+    fn coerce_join_keys_leaves_identical_types_untouched() {
+        let left = relation("l", &[("a", DataType::Utf8)]);
+        let right = relation("r", &[("b", DataType::Utf8)]);
+        let on = vec![(col("l", "a"), col("r", "b"))];
+        let coerced = JoinRewriter::coerce_join_keys(on.clone(), &left, &right).unwrap();
+        assert_eq!(coerced, on);
+    }
+
+    #[test]
+    fn coerce_join_keys_errors_without_a_common_type() {
+        let left = relation("l", &[("a", DataType::Int32)]);
+        let right = relation("r", &[("b", DataType::Binary)]);
+        let on = vec![(col("l", "a"), col("r", "b"))];
+        assert!(JoinRewriter::coerce_join_keys(on, &left, &right).is_err());
+    }
+
+    #[test]
+    fn conjuncts_splits_only_top_level_and() {
+        let expr = col("l", "a").eq(col("r", "a")).and(
+            col("l", "b")
+                .gt(col("r", "b"))
+                .or(col("l", "c").lt(col("r", "c"))),
+        );
+        let conjuncts = JoinRewriter::conjuncts(expr);
+        assert_eq!(conjuncts.len(), 2);
+    }
+
+    #[test]
+    fn split_single_sided_filters_excludes_key_columns() {
+        let left = relation("l", &[("a", DataType::Int32), ("_key_0", DataType::Int32)]);
+        let right = relation("r", &[("b", DataType::Int32)]);
+        let filter = col("l", "a")
+            .gt_eq(Expr::Literal(ScalarValue::Int32(Some(5))))
+            .and(col("l", "_key_0").eq(Expr::Literal(ScalarValue::Int32(Some(1)))))
+            .and(col("r", "b").lt(Expr::Literal(ScalarValue::Int32(Some(10)))));
+
+        let (left_only, right_only, remaining) =
+            JoinRewriter::split_single_sided_filters(filter, &left, &right).unwrap();
+        assert!(left_only.is_some());
+        assert!(right_only.is_some());
+        // the `_key_0` conjunct references a synthetic join key column, so it must
+        // stay on the join filter rather than be pushed into `left`.
+        assert!(remaining.is_some());
+    }
+
+    #[test]
+    fn null_safe_key_expr_wraps_in_a_case_over_is_null() {
+        let expr = JoinRewriter::null_safe_key_expr(col("l", "a"));
+        assert!(matches!(expr, Expr::Case(_)));
+    }
+}